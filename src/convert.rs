@@ -0,0 +1,254 @@
+use crate::error::ServiceError;
+use crate::spec::OpenApi30Spec;
+use crate::OpenApiService;
+
+impl OpenApiService {
+    /// Parses a document in any supported dialect (Swagger 2.0 / OpenAPI 3.0
+    /// / OpenAPI 3.1) and returns a unified `OpenApi30Spec`.
+    ///
+    /// Reads the dialect marker field (`swagger` or `openapi`) first, then
+    /// runs the matching upgrade/normalization pass, so callers only ever
+    /// have to deal with the 3.0 model.
+    pub fn parse_openapi_any(&self, content: &str) -> Result<OpenApi30Spec, ServiceError> {
+        let raw: serde_json::Value = serde_json::from_str(content)
+            .or_else(|_| serde_yaml::from_str(content))
+            .map_err(|e| {
+                ServiceError::ParseError(format!("unable to parse document as JSON or YAML: {:?}", e))
+            })?;
+
+        if let Some(swagger) = raw.get("swagger").and_then(|v| v.as_str()) {
+            if swagger.starts_with("2.") {
+                return self.upgrade_swagger2(raw);
+            }
+        }
+
+        if let Some(openapi) = raw.get("openapi").and_then(|v| v.as_str()) {
+            if openapi.starts_with("3.1") {
+                return self.downgrade_openapi31(raw);
+            }
+        }
+
+        // Already a 3.0 document (or no dialect marker at all) — reuse the
+        // value we just parsed instead of re-parsing `content` from scratch.
+        serde_json::from_value(raw)
+            .map_err(|e| ServiceError::ParseError(format!("failed to convert document into OpenApi30Spec: {:?}", e)))
+    }
+
+    /// Migrates a Swagger 2.0 document into `OpenApi30Spec`:
+    /// `definitions`/`parameters`/`responses` move into `components`, the
+    /// top-level `host` + `basePath` + `schemes` collapse into the 3.0
+    /// `servers` list, and every `$ref` pointer is rewritten to its new
+    /// location.
+    fn upgrade_swagger2(&self, mut raw: serde_json::Value) -> Result<OpenApi30Spec, ServiceError> {
+        let obj = raw.as_object_mut().ok_or_else(|| {
+            ServiceError::ParseError("swagger 2.0 document root must be an object".to_string())
+        })?;
+
+        let mut components = obj
+            .remove("components")
+            .and_then(|v| v.as_object().cloned())
+            .unwrap_or_default();
+
+        if let Some(definitions) = obj.remove("definitions") {
+            components.insert("schemas".to_string(), definitions);
+        }
+        if let Some(parameters) = obj.remove("parameters") {
+            components.insert("parameters".to_string(), parameters);
+        }
+        if let Some(responses) = obj.remove("responses") {
+            components.insert("responses".to_string(), responses);
+        }
+        obj.insert("components".to_string(), serde_json::Value::Object(components));
+
+        let host = obj.remove("host").and_then(|v| v.as_str().map(str::to_string));
+        let base_path = obj.remove("basePath").and_then(|v| v.as_str().map(str::to_string));
+        let schemes = obj.remove("schemes").and_then(|v| v.as_array().cloned()).unwrap_or_default();
+
+        if let Some(host) = host {
+            let base_path = base_path.unwrap_or_default();
+            let urls: Vec<serde_json::Value> = if schemes.is_empty() {
+                vec![serde_json::json!({ "url": format!("https://{}{}", host, base_path) })]
+            } else {
+                schemes
+                    .iter()
+                    .filter_map(|s| s.as_str())
+                    .map(|scheme| serde_json::json!({ "url": format!("{}://{}{}", scheme, host, base_path) }))
+                    .collect()
+            };
+            obj.insert("servers".to_string(), serde_json::Value::Array(urls));
+        }
+
+        obj.remove("swagger");
+        obj.insert("openapi".to_string(), serde_json::Value::String("3.0.3".to_string()));
+
+        Self::rewrite_swagger2_refs(&mut raw);
+
+        serde_json::from_value(raw)
+            .map_err(|e| ServiceError::ParseError(format!("failed to convert swagger 2.0 document: {:?}", e)))
+    }
+
+    /// Recursively rewrites Swagger 2.0 `$ref` pointers to their new
+    /// location under the migrated `components`:
+    /// `#/definitions/X` -> `#/components/schemas/X`,
+    /// `#/parameters/X` -> `#/components/parameters/X`,
+    /// `#/responses/X` -> `#/components/responses/X`.
+    fn rewrite_swagger2_refs(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let Some(serde_json::Value::String(pointer)) = map.get_mut("$ref") {
+                    if let Some(name) = pointer.strip_prefix("#/definitions/") {
+                        *pointer = format!("#/components/schemas/{}", name);
+                    } else if let Some(name) = pointer.strip_prefix("#/parameters/") {
+                        *pointer = format!("#/components/parameters/{}", name);
+                    } else if let Some(name) = pointer.strip_prefix("#/responses/") {
+                        *pointer = format!("#/components/responses/{}", name);
+                    }
+                }
+
+                for v in map.values_mut() {
+                    Self::rewrite_swagger2_refs(v);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items.iter_mut() {
+                    Self::rewrite_swagger2_refs(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Normalizes an OpenAPI 3.1 document to 3.0 conventions: collapses
+    /// `type: ["string", "null"]` arrays into a single `type` plus
+    /// `nullable: true`, and converts the numeric form of
+    /// `exclusiveMinimum`/`exclusiveMaximum` back into 3.0's boolean flags
+    /// paired with `minimum`/`maximum`.
+    fn downgrade_openapi31(&self, mut raw: serde_json::Value) -> Result<OpenApi30Spec, ServiceError> {
+        if let Some(obj) = raw.as_object_mut() {
+            obj.insert("openapi".to_string(), serde_json::Value::String("3.0.3".to_string()));
+        }
+        Self::normalize_schema_dialect(&mut raw)?;
+
+        serde_json::from_value(raw)
+            .map_err(|e| ServiceError::ParseError(format!("failed to convert openapi 3.1 document: {:?}", e)))
+    }
+
+    fn normalize_schema_dialect(value: &mut serde_json::Value) -> Result<(), ServiceError> {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let Some(serde_json::Value::Array(types)) = map.get("type").cloned() {
+                    let mut non_null: Vec<_> = types.iter().filter_map(|t| t.as_str()).filter(|t| *t != "null").collect();
+                    if non_null.len() != 1 {
+                        return Err(ServiceError::UnsupportedSchema(format!(
+                            "3.0 does not support multi-type schemas; cannot convert `type: {:?}` to a single type",
+                            types
+                        )));
+                    }
+
+                    let nullable = types.len() != non_null.len();
+                    map.insert("type".to_string(), serde_json::Value::String(non_null.remove(0).to_string()));
+                    if nullable {
+                        map.insert("nullable".to_string(), serde_json::Value::Bool(true));
+                    }
+                }
+
+                for key in ["exclusiveMinimum", "exclusiveMaximum"] {
+                    if let Some(bound) = map.get(key).and_then(|v| v.as_f64()) {
+                        let target = if key == "exclusiveMinimum" { "minimum" } else { "maximum" };
+                        map.insert(target.to_string(), serde_json::json!(bound));
+                        map.insert(key.to_string(), serde_json::Value::Bool(true));
+                    }
+                }
+
+                for v in map.values_mut() {
+                    Self::normalize_schema_dialect(v)?;
+                }
+                Ok(())
+            }
+            serde_json::Value::Array(items) => {
+                for item in items.iter_mut() {
+                    Self::normalize_schema_dialect(item)?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> OpenApiService {
+        OpenApiService::new()
+    }
+
+    #[test]
+    fn passes_plain_30_documents_through_unchanged() {
+        let content = r#"{"openapi": "3.0.3", "info": {}, "paths": {"/pets": {}}}"#;
+        let spec = service().parse_openapi_any(content).unwrap();
+        assert_eq!(spec.openapi, "3.0.3");
+    }
+
+    #[test]
+    fn upgrades_swagger2_and_rewrites_refs() {
+        let swagger = serde_json::json!({
+            "swagger": "2.0",
+            "info": {},
+            "host": "api.example.com",
+            "basePath": "/v1",
+            "schemes": ["https"],
+            "paths": {
+                "/pets": { "$ref": "#/definitions/Pet" }
+            },
+            "definitions": {
+                "Pet": { "type": "object" }
+            }
+        });
+
+        let spec = service().upgrade_swagger2(swagger).unwrap();
+        assert_eq!(spec.paths["/pets"]["$ref"], "#/components/schemas/Pet");
+        assert_eq!(spec.servers[0]["url"], "https://api.example.com/v1");
+
+        // The rewritten $ref must actually resolve.
+        let resolved = service().resolve_refs(spec).unwrap();
+        assert_eq!(resolved.paths["/pets"]["type"], "object");
+    }
+
+    #[test]
+    fn rejects_multi_type_unions_from_openapi31() {
+        let doc = serde_json::json!({
+            "openapi": "3.1.0",
+            "info": {},
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Mixed": { "type": ["string", "integer"] }
+                }
+            }
+        });
+
+        let err = service().downgrade_openapi31(doc).unwrap_err();
+        assert!(matches!(err, ServiceError::UnsupportedSchema(_)));
+    }
+
+    #[test]
+    fn collapses_nullable_union_to_30_convention() {
+        let doc = serde_json::json!({
+            "openapi": "3.1.0",
+            "info": {},
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Name": { "type": ["string", "null"] }
+                }
+            }
+        });
+
+        let spec = service().downgrade_openapi31(doc).unwrap();
+        let name = &spec.components.unwrap().schemas["Name"];
+        assert_eq!(name.schema_type.as_deref(), Some("string"));
+        assert_eq!(name.nullable, Some(true));
+    }
+}
@@ -0,0 +1,52 @@
+/// Errors produced while loading or parsing an OpenAPI document.
+#[derive(Debug)]
+pub enum ServiceError {
+    /// A parse failure without a precise source location.
+    ParseError(String),
+    /// A parse failure with the attempted format, its line/column, the
+    /// underlying message, and a source snippet with a caret pointing at the
+    /// failing column.
+    ParseDetail {
+        format: &'static str,
+        line: usize,
+        column: usize,
+        message: String,
+        snippet: String,
+    },
+    /// A `$ref` chain that loops back on a pointer already being resolved.
+    CyclicReference(String),
+    /// `$ref` resolution recursed past the configured maximum depth.
+    MaxDepthExceeded { max_depth: usize },
+    /// A schema construct this crate does not (yet) know how to convert
+    /// between OpenAPI dialects, e.g. a 3.1 multi-type union with no single
+    /// non-null member.
+    UnsupportedSchema(String),
+}
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServiceError::ParseError(message) => write!(f, "{}", message),
+            ServiceError::ParseDetail {
+                format,
+                line,
+                column,
+                message,
+                snippet,
+            } => write!(
+                f,
+                "{} parse error at line {}, column {}: {}\n{}",
+                format, line, column, message, snippet
+            ),
+            ServiceError::CyclicReference(pointer) => {
+                write!(f, "cyclic $ref detected: {}", pointer)
+            }
+            ServiceError::MaxDepthExceeded { max_depth } => {
+                write!(f, "exceeded max $ref resolution depth of {}", max_depth)
+            }
+            ServiceError::UnsupportedSchema(message) => write!(f, "unsupported schema: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
@@ -0,0 +1,21 @@
+mod convert;
+mod error;
+mod loader;
+mod parse;
+mod resolve;
+mod spec;
+mod stream;
+
+pub use error::ServiceError;
+pub use spec::{Components, OpenApi30Spec, Schema};
+
+/// Entry point for loading, parsing, resolving, and normalizing OpenAPI
+/// documents.
+#[derive(Debug, Default)]
+pub struct OpenApiService;
+
+impl OpenApiService {
+    pub fn new() -> Self {
+        Self
+    }
+}
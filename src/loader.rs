@@ -0,0 +1,136 @@
+use crate::error::ServiceError;
+use crate::parse::Format;
+use crate::spec::OpenApi30Spec;
+use crate::OpenApiService;
+
+impl OpenApiService {
+    /// Loads and parses an OpenAPI document from a file path.
+    ///
+    /// Picks the format to try first from the file extension (`.json` tries
+    /// JSON first, `.yaml`/`.yml` tries YAML first; anything else, including
+    /// no extension, defaults to JSON like `parse_openapi_content`), falling
+    /// back to the other format on failure.
+    pub fn from_path(&self, path: impl AsRef<std::path::Path>) -> Result<OpenApi30Spec, ServiceError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            ServiceError::ParseError(format!("failed to read {}: {:?}", path.display(), e))
+        })?;
+
+        let order = Self::format_for_extension(path.extension().and_then(|ext| ext.to_str()));
+        Self::parse_with_order(&content, order)
+    }
+
+    /// Loads and parses an OpenAPI document from any `Read` implementation.
+    pub fn from_reader(&self, mut r: impl std::io::Read) -> Result<OpenApi30Spec, ServiceError> {
+        let mut content = String::new();
+        r.read_to_string(&mut content)
+            .map_err(|e| ServiceError::ParseError(format!("failed to read from reader: {:?}", e)))?;
+
+        self.parse_openapi_content(&content)
+    }
+
+    /// Fetches and parses an OpenAPI document from a remote URL (requires the
+    /// `remote` feature).
+    ///
+    /// Same as `from_path`: picks the format to try first from the URL's
+    /// suffix, defaulting to JSON otherwise.
+    #[cfg(feature = "remote")]
+    pub fn from_url(&self, url: &str) -> Result<OpenApi30Spec, ServiceError> {
+        let response = reqwest::blocking::get(url)
+            .map_err(|e| ServiceError::ParseError(format!("failed to fetch {}: {:?}", url, e)))?;
+
+        let content = response.text().map_err(|e| {
+            ServiceError::ParseError(format!("failed to read response body from {}: {:?}", url, e))
+        })?;
+
+        Self::parse_with_order(&content, Self::format_for_url_suffix(url))
+    }
+
+    /// Decides which format to try first from a file extension (`None` for
+    /// no extension or a non-UTF-8 one). Shared by `from_path`.
+    fn format_for_extension(ext: Option<&str>) -> Format {
+        match ext {
+            Some("yaml") | Some("yml") => Format::Yaml,
+            _ => Format::Json,
+        }
+    }
+
+    /// Decides which format to try first from a URL's suffix. Shared by
+    /// `from_url`; kept separate from `format_for_extension` since it reads
+    /// a `.yaml`/`.yml` suffix directly off the URL string rather than a
+    /// `Path` extension.
+    #[cfg(feature = "remote")]
+    fn format_for_url_suffix(url: &str) -> Format {
+        if url.ends_with(".yaml") || url.ends_with(".yml") {
+            Format::Yaml
+        } else {
+            Format::Json
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn service() -> OpenApiService {
+        OpenApiService::new()
+    }
+
+    #[test]
+    fn from_reader_parses_json() {
+        let content = r#"{"openapi": "3.0.3", "info": {}, "paths": {}}"#;
+        let spec = service().from_reader(Cursor::new(content)).unwrap();
+        assert_eq!(spec.openapi, "3.0.3");
+    }
+
+    #[test]
+    fn from_path_prefers_yaml_for_yml_extension() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("stepflow-test-loader-{}.yml", std::process::id()));
+        std::fs::write(&path, "openapi: 3.0.3\ninfo: {}\npaths: {}\n").unwrap();
+
+        let spec = service().from_path(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(spec.openapi, "3.0.3");
+    }
+
+    #[test]
+    fn from_path_reports_parse_detail_for_malformed_json() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("stepflow-test-loader-{}.json", std::process::id()));
+        std::fs::write(&path, "{ this is not json").unwrap();
+
+        let err = service().from_path(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(err, ServiceError::ParseDetail { .. }));
+    }
+
+    #[test]
+    fn format_for_extension_prefers_yaml_for_yml_and_yaml() {
+        assert!(matches!(OpenApiService::format_for_extension(Some("yml")), Format::Yaml));
+        assert!(matches!(OpenApiService::format_for_extension(Some("yaml")), Format::Yaml));
+        assert!(matches!(OpenApiService::format_for_extension(Some("json")), Format::Json));
+        assert!(matches!(OpenApiService::format_for_extension(None), Format::Json));
+    }
+
+    #[cfg(feature = "remote")]
+    #[test]
+    fn format_for_url_suffix_prefers_yaml_for_yaml_and_yml_urls() {
+        assert!(matches!(
+            OpenApiService::format_for_url_suffix("https://example.com/spec.yaml"),
+            Format::Yaml
+        ));
+        assert!(matches!(
+            OpenApiService::format_for_url_suffix("https://example.com/spec.yml"),
+            Format::Yaml
+        ));
+        assert!(matches!(
+            OpenApiService::format_for_url_suffix("https://example.com/spec.json"),
+            Format::Json
+        ));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::error::ServiceError;
+use crate::spec::OpenApi30Spec;
+use crate::OpenApiService;
+
+/// Which format to try first when a document's dialect isn't already known.
+pub(crate) enum Format {
+    Json,
+    Yaml,
+}
+
+impl OpenApiService {
+    /// Parses OpenAPI content, trying JSON first and falling back to YAML.
+    ///
+    /// If both fail, returns a `ServiceError::ParseDetail` carrying the
+    /// line/column and a source snippet, instead of printing the error to
+    /// stderr.
+    pub fn parse_openapi_content(&self, content: &str) -> Result<OpenApi30Spec, ServiceError> {
+        Self::parse_with_order(content, Format::Json)
+    }
+
+    /// Parses OpenAPI content, guessing the format from its first
+    /// non-whitespace character (`{`/`[` is treated as JSON) and trying that
+    /// format first before falling back to the other. Fails with a
+    /// `ServiceError::ParseDetail` (line/column plus a source snippet) when
+    /// neither format parses.
+    pub fn parse_openapi_content_debug(&self, content: &str) -> Result<OpenApi30Spec, ServiceError> {
+        let trimmed = content.trim();
+        let is_likely_json = trimmed.starts_with('{') || trimmed.starts_with('[');
+        let order = if is_likely_json { Format::Json } else { Format::Yaml };
+        Self::parse_with_order(content, order)
+    }
+
+    /// Tries both formats in turn (`first` decides which goes first),
+    /// returning a `ServiceError::ParseDetail` if neither succeeds.
+    /// `from_path`/`from_url` (see `loader.rs`) pick `first` from the
+    /// file/URL extension and reuse this same diagnostic logic.
+    pub(crate) fn parse_with_order(content: &str, first: Format) -> Result<OpenApi30Spec, ServiceError> {
+        match first {
+            Format::Json => match serde_json::from_str::<OpenApi30Spec>(content) {
+                Ok(spec) => Ok(spec),
+                Err(json_err) => match serde_yaml::from_str::<OpenApi30Spec>(content) {
+                    Ok(spec) => Ok(spec),
+                    Err(yaml_err) => {
+                        // Both formats failed to parse; prefer the YAML error's
+                        // location since serde_yaml locates plain-text format
+                        // errors more precisely.
+                        let (format, line, column) = match yaml_err.location() {
+                            Some(loc) => ("yaml", loc.line(), loc.column()),
+                            None => ("json", json_err.line(), json_err.column()),
+                        };
+                        Err(Self::parse_detail(
+                            content,
+                            format,
+                            line,
+                            column,
+                            format!("JSON parse failed: {:?}, YAML parse failed: {:?}", json_err, yaml_err),
+                        ))
+                    }
+                },
+            },
+            Format::Yaml => match serde_yaml::from_str::<OpenApi30Spec>(content) {
+                Ok(spec) => Ok(spec),
+                Err(yaml_err) => match serde_json::from_str::<OpenApi30Spec>(content) {
+                    Ok(spec) => Ok(spec),
+                    Err(json_err) => {
+                        let (line, column) = yaml_err.location().map(|loc| (loc.line(), loc.column())).unwrap_or((0, 0));
+                        Err(Self::parse_detail(
+                            content,
+                            "yaml",
+                            line,
+                            column,
+                            format!("YAML parse failed: {:?}, JSON parse failed: {:?}", yaml_err, json_err),
+                        ))
+                    }
+                },
+            },
+        }
+    }
+
+    /// Builds a parse error carrying the line/column, a source snippet, and
+    /// a caret pointing at the failing column.
+    pub(crate) fn parse_detail(
+        content: &str,
+        format: &'static str,
+        line: usize,
+        column: usize,
+        message: String,
+    ) -> ServiceError {
+        ServiceError::ParseDetail {
+            format,
+            line,
+            column,
+            message,
+            snippet: Self::snippet_with_caret(content, line, column),
+        }
+    }
+
+    /// Pulls out the source line the error occurred on and draws a caret
+    /// `^` under the failing column.
+    pub(crate) fn snippet_with_caret(content: &str, line: usize, column: usize) -> String {
+        let source_line = content.lines().nth(line.saturating_sub(1)).unwrap_or_default();
+        let caret = format!("{}^", " ".repeat(column.saturating_sub(1)));
+        format!("{}\n{}", source_line, caret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> OpenApiService {
+        OpenApiService::new()
+    }
+
+    #[test]
+    fn parses_json_content() {
+        let content = r#"{"openapi": "3.0.3", "info": {}, "paths": {}}"#;
+        let spec = service().parse_openapi_content(content).unwrap();
+        assert_eq!(spec.openapi, "3.0.3");
+    }
+
+    #[test]
+    fn falls_back_from_json_to_yaml() {
+        let content = "openapi: 3.0.3\ninfo: {}\npaths: {}\n";
+        let spec = service().parse_openapi_content(content).unwrap();
+        assert_eq!(spec.openapi, "3.0.3");
+    }
+
+    #[test]
+    fn reports_parse_detail_on_invalid_content() {
+        let content = "{ not valid json or yaml: [}";
+        let err = service().parse_openapi_content(content).unwrap_err();
+        match err {
+            ServiceError::ParseDetail { line, column, .. } => {
+                assert!(line >= 1);
+                assert!(column >= 1);
+            }
+            other => panic!("expected ParseDetail, got {:?}", other),
+        }
+    }
+}
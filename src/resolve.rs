@@ -0,0 +1,180 @@
+use crate::error::ServiceError;
+use crate::spec::OpenApi30Spec;
+use crate::OpenApiService;
+
+impl OpenApiService {
+    /// Resolves `$ref` pointers and returns a fully-expanded `OpenApi30Spec`.
+    ///
+    /// Walks the whole document, replacing JSON Pointer references such as
+    /// `#/components/schemas/...` and `#/components/parameters/...` with the
+    /// node they point at under `components`. Detects cyclic references
+    /// (`ServiceError::CyclicReference`) and bounds the number of `$ref` hops
+    /// followed in one chain via `max_depth`; plain object/array nesting
+    /// without a `$ref` is not counted against that limit.
+    pub fn resolve_refs(&self, spec: OpenApi30Spec) -> Result<OpenApi30Spec, ServiceError> {
+        const DEFAULT_MAX_DEPTH: usize = 64;
+        self.resolve_refs_with_depth(spec, DEFAULT_MAX_DEPTH)
+    }
+
+    /// Same as `resolve_refs`, but lets the caller configure the maximum
+    /// number of chained `$ref` hops.
+    pub fn resolve_refs_with_depth(
+        &self,
+        spec: OpenApi30Spec,
+        max_depth: usize,
+    ) -> Result<OpenApi30Spec, ServiceError> {
+        let root = serde_json::to_value(&spec).map_err(|e| {
+            ServiceError::ParseError(format!("failed to serialize spec for ref resolution: {:?}", e))
+        })?;
+
+        let mut seen = Vec::new();
+        let resolved = Self::resolve_value(&root, &root, &mut seen, max_depth)?;
+
+        serde_json::from_value(resolved).map_err(|e| {
+            ServiceError::ParseError(format!("failed to rebuild spec after ref resolution: {:?}", e))
+        })
+    }
+
+    /// Parses with `parse_openapi_content` and immediately resolves all
+    /// internal references.
+    pub fn parse_openapi_content_resolved(&self, content: &str) -> Result<OpenApi30Spec, ServiceError> {
+        let spec = self.parse_openapi_content(content)?;
+        self.resolve_refs(spec)
+    }
+
+    /// `max_depth` bounds the number of `$ref` hops followed (`seen.len()`),
+    /// not the plain object/array nesting of the document — an ordinary
+    /// deeply-nested, ref-free schema must not trip this check.
+    fn resolve_value(
+        root: &serde_json::Value,
+        node: &serde_json::Value,
+        seen: &mut Vec<String>,
+        max_depth: usize,
+    ) -> Result<serde_json::Value, ServiceError> {
+        match node {
+            serde_json::Value::Object(map) => {
+                if let Some(serde_json::Value::String(pointer)) = map.get("$ref") {
+                    if seen.iter().any(|p| p == pointer) {
+                        return Err(ServiceError::CyclicReference(pointer.clone()));
+                    }
+                    if seen.len() >= max_depth {
+                        return Err(ServiceError::MaxDepthExceeded { max_depth });
+                    }
+
+                    let target = Self::lookup_pointer(root, pointer).ok_or_else(|| {
+                        ServiceError::ParseError(format!("unresolved $ref: {}", pointer))
+                    })?;
+
+                    seen.push(pointer.clone());
+                    let resolved = Self::resolve_value(root, target, seen, max_depth)?;
+                    seen.pop();
+                    return Ok(resolved);
+                }
+
+                let mut out = serde_json::Map::with_capacity(map.len());
+                for (key, value) in map {
+                    out.insert(key.clone(), Self::resolve_value(root, value, seen, max_depth)?);
+                }
+                Ok(serde_json::Value::Object(out))
+            }
+            serde_json::Value::Array(items) => {
+                let mut out = Vec::with_capacity(items.len());
+                for item in items {
+                    out.push(Self::resolve_value(root, item, seen, max_depth)?);
+                }
+                Ok(serde_json::Value::Array(out))
+            }
+            other => Ok(other.clone()),
+        }
+    }
+
+    /// Looks up the target node for a JSON Pointer (e.g.
+    /// `#/components/schemas/Pet`).
+    fn lookup_pointer<'a>(root: &'a serde_json::Value, pointer: &str) -> Option<&'a serde_json::Value> {
+        let pointer = pointer.strip_prefix('#')?;
+        root.pointer(pointer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> OpenApiService {
+        OpenApiService::new()
+    }
+
+    fn spec_with_paths(paths: serde_json::Value) -> OpenApi30Spec {
+        serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": {},
+            "paths": paths,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn resolves_a_ref_into_components() {
+        let mut spec = spec_with_paths(serde_json::json!({
+            "/pets": { "$ref": "#/components/schemas/Pet" }
+        }));
+        spec.components = Some(serde_json::from_value(serde_json::json!({
+            "schemas": { "Pet": { "type": "object" } }
+        })).unwrap());
+
+        let resolved = service().resolve_refs(spec).unwrap();
+        assert_eq!(resolved.paths["/pets"]["type"], "object");
+    }
+
+    #[test]
+    fn rejects_cyclic_references() {
+        let mut spec = spec_with_paths(serde_json::json!({}));
+        spec.components = Some(serde_json::from_value(serde_json::json!({
+            "schemas": {
+                "A": { "$ref": "#/components/schemas/B" },
+                "B": { "$ref": "#/components/schemas/A" }
+            }
+        })).unwrap());
+
+        let err = service().resolve_refs(spec).unwrap_err();
+        assert!(matches!(err, ServiceError::CyclicReference(_)));
+    }
+
+    #[test]
+    fn rejects_refs_nested_past_max_depth() {
+        // Build a chain A -> B -> C -> ... deeper than the configured max depth.
+        let mut schemas = serde_json::Map::new();
+        let chain_len = 5;
+        for i in 0..chain_len {
+            let next = format!("#/components/schemas/S{}", i + 1);
+            schemas.insert(format!("S{}", i), serde_json::json!({ "$ref": next }));
+        }
+        schemas.insert(format!("S{}", chain_len), serde_json::json!({ "type": "string" }));
+
+        let mut spec = spec_with_paths(serde_json::json!({
+            "/chained": { "$ref": "#/components/schemas/S0" }
+        }));
+        spec.components = Some(serde_json::from_value(serde_json::json!({ "schemas": schemas })).unwrap());
+
+        let err = service().resolve_refs_with_depth(spec, 2).unwrap_err();
+        assert!(matches!(err, ServiceError::MaxDepthExceeded { max_depth: 2 }));
+    }
+
+    #[test]
+    fn ref_free_deep_nesting_does_not_trip_max_depth() {
+        // Plain object/array nesting, with no $ref anywhere, must not count
+        // against max_depth — only following a $ref should.
+        let mut nested = serde_json::json!({ "type": "string" });
+        for _ in 0..100 {
+            nested = serde_json::json!({ "type": "object", "properties": { "child": nested } });
+        }
+
+        let mut spec = spec_with_paths(serde_json::json!({}));
+        spec.components = Some(
+            serde_json::from_value(serde_json::json!({ "schemas": { "Deep": nested } })).unwrap(),
+        );
+
+        let resolved = service().resolve_refs_with_depth(spec, 2).unwrap();
+        assert_eq!(resolved.components.unwrap().schemas["Deep"].schema_type.as_deref(), Some("object"));
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A materialized OpenAPI 3.0 document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenApi30Spec {
+    pub openapi: String,
+    #[serde(default)]
+    pub info: serde_json::Value,
+    #[serde(default)]
+    pub paths: serde_json::Value,
+    #[serde(default)]
+    pub servers: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub components: Option<Components>,
+}
+
+/// The `components` section of an OpenAPI 3.0 document.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Components {
+    #[serde(default)]
+    pub schemas: HashMap<String, Schema>,
+    #[serde(default)]
+    pub parameters: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub responses: HashMap<String, serde_json::Value>,
+}
+
+/// A JSON Schema node as used under `components.schemas` (and nested within
+/// `properties`/`items`). Follows the OpenAPI 3.0 conventions: a single
+/// `type` string (not the 3.1 `type` array), and boolean
+/// `exclusiveMinimum`/`exclusiveMaximum` flags paired with `minimum`/`maximum`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Schema {
+    #[serde(rename = "$ref", default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub schema_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nullable: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f64>,
+    #[serde(rename = "exclusiveMinimum", default, skip_serializing_if = "Option::is_none")]
+    pub exclusive_minimum: Option<bool>,
+    #[serde(rename = "exclusiveMaximum", default, skip_serializing_if = "Option::is_none")]
+    pub exclusive_maximum: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<Schema>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<HashMap<String, Schema>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,110 @@
+use crate::error::ServiceError;
+use crate::spec::OpenApi30Spec;
+use crate::OpenApiService;
+
+enum DocumentFormat {
+    Json,
+    Yaml,
+}
+
+impl OpenApiService {
+    /// Parses an OpenAPI document directly from a `Read` stream, avoiding
+    /// reading the whole file into a `String` first.
+    ///
+    /// Peeks at the first non-whitespace byte via `BufReader::fill_buf` to
+    /// decide the format (`{`/`[` is treated as JSON, everything else as
+    /// YAML), then hands the same reader to `serde_json::from_reader` /
+    /// `serde_yaml::from_reader` with no extra copy. On failure this still
+    /// returns a `ServiceError::ParseDetail`: since the full document is no
+    /// longer kept in memory, the source snippet only covers the prefix
+    /// already buffered during sniffing (at most the `BufReader`'s default
+    /// buffer size) — a failure past that prefix still carries line/column,
+    /// but its snippet has an empty source line above the caret.
+    pub fn parse_openapi_reader<R: std::io::Read>(&self, r: R) -> Result<OpenApi30Spec, ServiceError> {
+        let mut reader = std::io::BufReader::new(r);
+        let format = Self::sniff_format(&mut reader)?;
+        let prefix = Self::peek_buffered_prefix(&mut reader);
+
+        match format {
+            DocumentFormat::Json => serde_json::from_reader(reader).map_err(|e| {
+                Self::parse_detail(
+                    &prefix,
+                    "json",
+                    e.line(),
+                    e.column(),
+                    format!("JSON stream parse failed: {:?}", e),
+                )
+            }),
+            DocumentFormat::Yaml => serde_yaml::from_reader(reader).map_err(|e| {
+                let (line, column) = e.location().map(|loc| (loc.line(), loc.column())).unwrap_or((0, 0));
+                Self::parse_detail(
+                    &prefix,
+                    "yaml",
+                    line,
+                    column,
+                    format!("YAML stream parse failed: {:?}", e),
+                )
+            }),
+        }
+    }
+
+    /// Peeks at the buffer without consuming any bytes, deciding the
+    /// document's format from its first non-whitespace character.
+    fn sniff_format<R: std::io::Read>(reader: &mut std::io::BufReader<R>) -> Result<DocumentFormat, ServiceError> {
+        use std::io::BufRead;
+
+        loop {
+            let buf = reader
+                .fill_buf()
+                .map_err(|e| ServiceError::ParseError(format!("failed to sniff stream: {:?}", e)))?;
+
+            match buf.first() {
+                None => return Ok(DocumentFormat::Yaml),
+                Some(b) if b.is_ascii_whitespace() => reader.consume(1),
+                Some(b'{') | Some(b'[') => return Ok(DocumentFormat::Json),
+                Some(_) => return Ok(DocumentFormat::Yaml),
+            }
+        }
+    }
+
+    /// Copies the `BufReader`'s currently filled buffer, used to build a
+    /// source snippet if parsing later fails.
+    fn peek_buffered_prefix<R: std::io::Read>(reader: &mut std::io::BufReader<R>) -> String {
+        use std::io::BufRead;
+        match reader.fill_buf() {
+            Ok(buf) => String::from_utf8_lossy(buf).into_owned(),
+            Err(_) => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn service() -> OpenApiService {
+        OpenApiService::new()
+    }
+
+    #[test]
+    fn sniffs_and_parses_json_stream() {
+        let content = r#"{"openapi": "3.0.3", "info": {}, "paths": {}}"#;
+        let spec = service().parse_openapi_reader(Cursor::new(content)).unwrap();
+        assert_eq!(spec.openapi, "3.0.3");
+    }
+
+    #[test]
+    fn sniffs_and_parses_yaml_stream() {
+        let content = "openapi: 3.0.3\ninfo: {}\npaths: {}\n";
+        let spec = service().parse_openapi_reader(Cursor::new(content)).unwrap();
+        assert_eq!(spec.openapi, "3.0.3");
+    }
+
+    #[test]
+    fn reports_parse_detail_on_malformed_json_stream() {
+        let content = r#"{"openapi": "#;
+        let err = service().parse_openapi_reader(Cursor::new(content)).unwrap_err();
+        assert!(matches!(err, ServiceError::ParseDetail { format: "json", .. }));
+    }
+}